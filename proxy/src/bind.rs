@@ -1,13 +1,20 @@
 use std::error::Error;
 use std::fmt;
 use std::cmp;
+use std::collections::{HashMap, VecDeque};
 use std::marker::PhantomData;
+use std::mem;
 use std::net::SocketAddr;
-use std::sync::Arc;
-use std::sync::atomic::AtomicUsize;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 
+use futures::{Async, Future, Poll};
+use futures::task;
+use h2;
 use http::{self, header, uri};
-use tokio_core::reactor::Handle;
+use rand::{self, Rng};
+use tokio_core::reactor::{Handle, Timeout};
 use tower;
 use tower_h2;
 use tower_reconnect::Reconnect;
@@ -21,34 +28,484 @@ use transport;
 
 /// Binds a `Service` from a `SocketAddr`.
 ///
-/// The returned `Service` buffers request until a connection is established.
-///
-/// # TODO
-///
-/// Buffering is not bounded and no timeouts are applied.
+/// The returned `Service` buffers requests until a connection is established.
+/// In-flight concurrency and the per-request wait for a connection are bounded
+/// by [`Bind::with_buffer_capacity`] and [`Bind::with_request_timeout`].
 pub struct Bind<C, B> {
     ctx: C,
     sensors: telemetry::Sensors,
     executor: Handle,
     req_ids: Arc<AtomicUsize>,
+    backoff: Backoff,
+    buffer_capacity: Option<usize>,
+    request_timeout: Option<Duration>,
+    pool: Pool<B>,
+    retry_canceled: bool,
+    drain: Drain,
     _p: PhantomData<B>,
 }
 
+/// Coordinates graceful shutdown across every service a `Bind` constructs.
+///
+/// A single `Drain` is shared (like [`Backoff`] and [`Pool`]) by every
+/// `Service` a `Bind` hands out: it pairs a *signal* side, flipped once via
+/// [`Bind::drain_handle`], with a *watch* side polled by each service's
+/// `poll_ready`. Once signaled, services stop admitting new requests while
+/// every request already dispatched through them runs to completion.
+#[derive(Clone)]
+struct Drain {
+    shared: Arc<DrainShared>,
+}
+
+struct DrainShared {
+    /// Set once shutdown is signaled; watched by each service so that it stops
+    /// admitting new requests.
+    draining: AtomicBool,
+    /// The number of request futures still in flight across all services.
+    outstanding: AtomicUsize,
+    /// The task awaiting quiescence in [`Draining`], woken as the last
+    /// outstanding request completes.
+    task: Mutex<Option<task::Task>>,
+}
+
+/// A pool of idle backend connections, keyed by `(SocketAddr, Protocol)`.
+///
+/// HTTP/1.x opens a connection per host with no native multiplexing, so
+/// handing out idle connections on checkout and returning them once a response
+/// body completes avoids connection churn under load. HTTP/2 multiplexes on a
+/// single connection and HTTP/3 runs over QUIC, so neither is idle-pooled, and
+/// `Host::NoAuthority` is never pooled because it is never equal to itself.
+struct Pool<B> {
+    config: PoolConfig,
+    idle: Arc<Mutex<HashMap<PoolKey, VecDeque<Idle<B>>>>>,
+}
+
+/// The key under which idle connections are pooled: a target address plus the
+/// `Protocol` connection class, reusing `Host::Authority` equality rules.
+type PoolKey = (SocketAddr, Protocol);
+
+/// Tunables for the idle-connection [`Pool`].
+#[derive(Clone, Copy)]
+struct PoolConfig {
+    max_idle_per_host: usize,
+    idle_timeout: Duration,
+}
+
+/// An idle connection and the instant it was returned to the pool.
+struct Idle<B> {
+    client: Client<B>,
+    since: Instant,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        // `max_idle_per_host == 0` leaves pooling disabled until the caller
+        // opts in via `Bind::with_pool_config`.
+        PoolConfig {
+            max_idle_per_host: 0,
+            idle_timeout: Duration::from_secs(90),
+        }
+    }
+}
+
+impl<B> Clone for Pool<B> {
+    fn clone(&self) -> Self {
+        Pool {
+            config: self.config,
+            idle: self.idle.clone(),
+        }
+    }
+}
+
+impl<B> Pool<B> {
+    fn new(config: PoolConfig) -> Self {
+        Pool {
+            config,
+            idle: Default::default(),
+        }
+    }
+
+    /// Whether connections for `protocol` are eligible for idle pooling.
+    fn poolable(&self, protocol: &Protocol) -> bool {
+        self.config.max_idle_per_host > 0 && match *protocol {
+            Protocol::Http1(Host::Authority(_)) => true,
+            // NoAuthority is never equal to itself; Http2 multiplexes; Http3
+            // runs over QUIC. None of these are idle-pooled.
+            _ => false,
+        }
+    }
+
+    /// Checks an idle connection out for `key`, removing it from the pool and
+    /// discarding any that have been idle longer than the configured timeout.
+    /// Liveness against a peer that closed the connection mid-idle is verified
+    /// by the lessee's first `poll_ready`.
+    fn checkout(&self, key: &PoolKey) -> Option<Client<B>> {
+        if !self.poolable(&key.1) {
+            return None;
+        }
+
+        let mut idle = self.idle.lock().expect("pool lock poisoned");
+        let queue = idle.get_mut(key)?;
+        while let Some(entry) = queue.pop_front() {
+            if entry.since.elapsed() < self.config.idle_timeout {
+                return Some(entry.client);
+            }
+            // Otherwise the connection has been idle too long; drop it.
+        }
+        None
+    }
+
+    /// Returns a connection to the pool, up to `max_idle_per_host` per key.
+    fn store(&self, key: PoolKey, client: Client<B>) {
+        if !self.poolable(&key.1) {
+            return;
+        }
+
+        let mut idle = self.idle.lock().expect("pool lock poisoned");
+        let queue = idle.entry(key).or_insert_with(VecDeque::new);
+        if queue.len() < self.config.max_idle_per_host {
+            queue.push_back(Idle {
+                client,
+                since: Instant::now(),
+            });
+        }
+    }
+}
+
+/// A backend client decorated to lease its connection from the idle [`Pool`]
+/// for the duration of each request and return it once the response completes.
+///
+/// A single HTTP/1.x connection cannot serve two requests at once, so the
+/// connection is *leased*: [`poll_ready`] checks one out of the pool (or dials
+/// a fresh one on a miss), `call` hands that exact connection to the request —
+/// taking it out of `Pooled` so a second request cannot be dispatched on it —
+/// and the [`RecycleBody`] returns it to the pool only once the response body
+/// reaches end-of-stream. Because the connection is absent from the pool for
+/// the whole in-flight window, no two binds can ever share it.
+///
+/// [`poll_ready`]: #method.poll_ready
+pub struct Pooled<B> {
+    /// The connection leased for the current request, if one has been checked
+    /// out; `None` between requests, when the next `poll_ready` leases again.
+    inner: Option<Client<B>>,
+    /// Whether `inner` came from the pool (and so may have been closed by the
+    /// peer while idle) rather than being freshly dialed.
+    leased_from_pool: bool,
+    bind: Bind<Arc<ctx::Proxy>, B>,
+    key: PoolKey,
+}
+
+impl<B> tower::Service for Pooled<B>
+where
+    B: tower_h2::Body + 'static,
+{
+    type Request = <Client<B> as tower::Service>::Request;
+    type Response = http::Response<RecycleBody<HttpBody>>;
+    type Error = <Client<B> as tower::Service>::Error;
+    type Future = PooledFuture<B>;
+
+    fn poll_ready(&mut self) -> Poll<(), Self::Error> {
+        loop {
+            if self.inner.is_none() {
+                let (client, from_pool) = self.bind.lease(&self.key);
+                self.inner = Some(client);
+                self.leased_from_pool = from_pool;
+            }
+
+            match self.inner.as_mut().expect("leased above").poll_ready() {
+                Err(e) => {
+                    // A connection checked out of the pool may have been closed
+                    // by the peer while it sat idle; that is not a real error,
+                    // so drop it and dial a fresh one. A connection we dialed
+                    // ourselves failing is a genuine connect error.
+                    if self.leased_from_pool {
+                        trace!("discarding dead pooled connection addr={}", self.key.0);
+                        self.inner = Some(self.bind.connect_client(&self.key.0, &self.key.1));
+                        self.leased_from_pool = false;
+                        continue;
+                    }
+                    return Err(e);
+                }
+                ready => return ready,
+            }
+        }
+    }
+
+    fn call(&mut self, req: Self::Request) -> Self::Future {
+        // Take the leased connection out of `Pooled`: it is now busy serving
+        // this request and must not be handed to another until the response
+        // body drains and `Recycle` returns it to the pool. The next
+        // `poll_ready` leases a connection afresh.
+        let mut client = self.inner.take()
+            .expect("poll_ready must lease a connection before call");
+        let fut = client.call(req);
+        let recycle = Recycle {
+            bind: self.bind.clone(),
+            key: self.key.clone(),
+            client,
+        };
+        PooledFuture {
+            inner: fut,
+            recycle: Some(recycle),
+        }
+    }
+}
+
+/// Drives a [`Pooled`] request, attaching the recycle hook to its response.
+pub struct PooledFuture<B> {
+    inner: <Client<B> as tower::Service>::Future,
+    recycle: Option<Recycle<B>>,
+}
+
+impl<B> Future for PooledFuture<B>
+where
+    B: tower_h2::Body + 'static,
+{
+    type Item = http::Response<RecycleBody<HttpBody>>;
+    type Error = <Client<B> as tower::Service>::Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        let rsp = match self.inner.poll()? {
+            Async::Ready(rsp) => rsp,
+            Async::NotReady => return Ok(Async::NotReady),
+        };
+        // Type-erase the recycle hook so `RecycleBody` — and thus the concrete
+        // `HttpResponse` body — need not carry the request body parameter `B`.
+        let on_end = self.recycle.take().map(|recycle| {
+            let mut recycle = Some(recycle);
+            let hook: Box<FnMut() + Send> = Box::new(move || {
+                if let Some(recycle) = recycle.take() {
+                    recycle.run();
+                }
+            });
+            hook
+        });
+        Ok(Async::Ready(rsp.map(move |inner| RecycleBody {
+            inner,
+            on_end,
+        })))
+    }
+}
+
+/// Captures everything needed to return a connection to the pool: the shared
+/// `Bind`, the connection handle, and the key to file it under.
+struct Recycle<B> {
+    bind: Bind<Arc<ctx::Proxy>, B>,
+    key: PoolKey,
+    client: Client<B>,
+}
+
+impl<B> Recycle<B> {
+    fn run(self) {
+        let (addr, protocol) = self.key;
+        self.bind.recycle(addr, protocol, self.client);
+    }
+}
+
+/// A response body that returns its connection to the idle [`Pool`] once the
+/// underlying body reaches end-of-stream, delegating every other call.
+pub struct RecycleBody<T> {
+    inner: T,
+    on_end: Option<Box<FnMut() + Send>>,
+}
+
+impl<T: tower_h2::Body> tower_h2::Body for RecycleBody<T> {
+    type Data = T::Data;
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn poll_data(&mut self) -> Poll<Option<Self::Data>, h2::Error> {
+        let poll = self.inner.poll_data();
+        // A `None` data frame marks the end of the body, at which point the
+        // connection is idle again and can go back to the pool.
+        if let Ok(Async::Ready(None)) = poll {
+            if let Some(mut on_end) = self.on_end.take() {
+                on_end();
+            }
+        }
+        poll
+    }
+
+    fn poll_trailers(&mut self) -> Poll<Option<http::HeaderMap>, h2::Error> {
+        self.inner.poll_trailers()
+    }
+}
+
+/// Decorrelated-jitter backoff policy shared across rebinds of a target.
+///
+/// A flapping backend would otherwise be hammered with a tight reconnect
+/// loop; instead each failed connect to a given `SocketAddr` spaces out the
+/// next attempt, and a successful connect resets the delay back to `base`.
+/// The per-address sleep state is keyed so that each rebind of the same
+/// target resumes where its predecessor left off.
+#[derive(Clone)]
+struct Backoff {
+    base: Duration,
+    cap: Duration,
+    sleeps: Arc<Mutex<HashMap<SocketAddr, Duration>>>,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Backoff {
+            base: Duration::from_millis(50),
+            cap: Duration::from_secs(60),
+            sleeps: Default::default(),
+        }
+    }
+}
+
+impl Backoff {
+    /// Advances and returns the delay to wait before the next connect attempt
+    /// to `addr`, following the "decorrelated jitter" recurrence
+    /// `next = min(cap, random_between(base, prev * 3))`.
+    fn advance(&self, addr: &SocketAddr) -> Duration {
+        let mut sleeps = self.sleeps.lock().expect("backoff lock poisoned");
+        let prev = sleeps.get(addr).cloned().unwrap_or(self.base);
+        let high = cmp::max(prev * 3, self.base);
+        let next = cmp::min(self.cap, rand_between(self.base, high));
+        sleeps.insert(*addr, next);
+        next
+    }
+
+    /// Resets the backoff state for `addr` once a connection succeeds.
+    fn reset(&self, addr: &SocketAddr) {
+        self.sleeps.lock().expect("backoff lock poisoned").remove(addr);
+    }
+}
+
+/// Uniformly samples a `Duration` in `[low, high]`.
+fn rand_between(low: Duration, high: Duration) -> Duration {
+    if high <= low {
+        return low;
+    }
+    let span = dur_to_nanos(high) - dur_to_nanos(low);
+    let offset = rand::thread_rng().gen_range(0, span + 1);
+    low + nanos_to_dur(offset)
+}
+
+fn dur_to_nanos(d: Duration) -> u64 {
+    d.as_secs().saturating_mul(1_000_000_000).saturating_add(u64::from(d.subsec_nanos()))
+}
+
+fn nanos_to_dur(n: u64) -> Duration {
+    Duration::new(n / 1_000_000_000, (n % 1_000_000_000) as u32)
+}
+
+impl Default for Drain {
+    fn default() -> Self {
+        Drain {
+            shared: Arc::new(DrainShared {
+                draining: AtomicBool::new(false),
+                outstanding: AtomicUsize::new(0),
+                task: Mutex::new(None),
+            }),
+        }
+    }
+}
+
+impl Drain {
+    /// Whether graceful shutdown has been signaled.
+    fn is_draining(&self) -> bool {
+        self.shared.draining.load(Ordering::SeqCst)
+    }
+
+    /// Counts a newly dispatched request against the shared state, returning a
+    /// guard that deregisters it on drop.
+    fn guard(&self) -> DrainGuard {
+        self.shared.outstanding.fetch_add(1, Ordering::SeqCst);
+        DrainGuard {
+            shared: self.shared.clone(),
+        }
+    }
+
+    /// Signals shutdown and yields the future that resolves once every
+    /// outstanding request has drained.
+    fn signal(&self) -> Draining {
+        self.shared.draining.store(true, Ordering::SeqCst);
+        Draining {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+/// Keeps an in-flight request counted against the shared [`Drain`] for the
+/// lifetime of its future, waking a pending [`Draining`] once the last
+/// outstanding request completes.
+struct DrainGuard {
+    shared: Arc<DrainShared>,
+}
+
+impl Drop for DrainGuard {
+    fn drop(&mut self) {
+        // When the final outstanding request drains, wake the shutdown future.
+        if self.shared.outstanding.fetch_sub(1, Ordering::SeqCst) == 1 {
+            if let Some(task) = self.shared.task.lock().expect("drain lock poisoned").take() {
+                task.notify();
+            }
+        }
+    }
+}
+
+/// A future that resolves once every request dispatched before shutdown was
+/// signaled has run to completion. Returned by [`Bind::drain_handle`].
+pub struct Draining {
+    shared: Arc<DrainShared>,
+}
+
+impl Future for Draining {
+    type Item = ();
+    type Error = ();
+
+    fn poll(&mut self) -> Poll<(), ()> {
+        if self.shared.outstanding.load(Ordering::SeqCst) == 0 {
+            return Ok(Async::Ready(()));
+        }
+
+        // Park until the last outstanding request drops its guard, re-checking
+        // afterwards so a completion that raced the registration is not missed.
+        *self.shared.task.lock().expect("drain lock poisoned") = Some(task::current());
+        if self.shared.outstanding.load(Ordering::SeqCst) == 0 {
+            Ok(Async::Ready(()))
+        } else {
+            Ok(Async::NotReady)
+        }
+    }
+}
+
 /// Binds a `Service` from a `SocketAddr` for a pre-determined protocol.
 pub struct BindProtocol<C, B> {
     bind: Bind<C, B>,
     protocol: Protocol,
 }
 
+/// Binds a `Service` from a `SocketAddr`, detecting the protocol per request.
+///
+/// Unlike `BindProtocol`, the caller does not commit to a `Protocol` up front:
+/// each request's `Protocol` is derived from its version (HTTP/2) or `Host`
+/// (HTTP/1.x), so a single `Bind` implementation can serve mixed-protocol
+/// backends the way a combined HTTP/1.1+HTTP/2 service does.
+pub struct BindAuto<C, B> {
+    bind: Bind<C, B>,
+}
+
 /// Protocol portion of the `Recognize` key for a request.
 ///
-/// This marks whether to use HTTP/2 or HTTP/1.x for a request. In
-/// the case of HTTP/1.x requests, it also stores a "host" key to ensure
-/// that each host receives its own connection.
+/// This marks whether to use HTTP/1.x, HTTP/2, or HTTP/3 for a request. In
+/// the case of HTTP/1.x and HTTP/3 requests, it also stores a "host" key to
+/// ensure that each host receives its own connection; like `Http2`, `Http3`
+/// is its own connection class and is never reused across protocols.
+///
+/// `Http3` is kept as a distinct connection class for when a QUIC transport is
+/// wired in, but nothing constructs it yet: the proxy does not serve HTTP/3, so
+/// it neither advertises an `alt-svc` endpoint nor routes requests over h3.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Protocol {
     Http1(Host),
-    Http2
+    Http2,
+    Http3(Host),
 }
 
 #[derive(Clone, Debug, Eq, Hash)]
@@ -57,11 +514,128 @@ pub enum Host {
     NoAuthority,
 }
 
-pub type Service<B> = Reconnect<NewHttp<B>>;
+pub type Service<B> = Bounded<WithBackoff<BoundService<B>, B>, B>;
 
-pub type NewHttp<B> = sensor::NewHttp<Client<B>, B, HttpBody>;
+/// The reconnecting stack a [`BoundService`] drives and rebuilds in place.
+///
+/// Connect errors surface straight out of `Reconnect`, so the self-healing
+/// [`BoundService`] above it can observe them and rebind; the backoff spacing
+/// lives in the [`WithBackoff`] layer wrapping the `BoundService`, not here.
+type Stack<B> = Reconnect<NewHttp<B>>;
+
+/// Bounds the concurrency admitted to a service by count and time.
+///
+/// Caps the number of in-flight requests at a configured capacity, applying
+/// backpressure (`poll_ready` returns not-ready) once it is reached rather than
+/// dispatching more work, and fails any request that waits too long for a
+/// connection. Backpressure propagates to the request buffer above, so a
+/// stalled backend cannot drive unbounded concurrent work; the buffer's own
+/// queue length is bounded where the buffer is constructed. Errors are surfaced
+/// through [`BufferSpawnError`].
+pub struct Bounded<S, B> {
+    inner: S,
+    capacity: Option<usize>,
+    in_flight: Arc<AtomicUsize>,
+    /// The task blocked on capacity, woken by a completing [`BoundedFuture`] so
+    /// a backpressured `poll_ready` is re-polled once a slot frees.
+    waiters: Arc<Mutex<Option<task::Task>>>,
+    timeout: Option<Duration>,
+    /// Bounds how long `poll_ready` may wait for a connection before failing;
+    /// armed on the first not-ready poll and cleared once the inner service is
+    /// ready, so it never limits an established request's response stream.
+    waiting: Option<Timeout>,
+    handle: Handle,
+    drain: Drain,
+    _p: PhantomData<B>,
+}
+
+/// Drives an inner request future, keeping it counted against the drain and
+/// freeing its concurrency slot on completion.
+pub struct BoundedFuture<F> {
+    inner: F,
+    in_flight: Arc<AtomicUsize>,
+    /// Woken once this request completes so a capacity-blocked `poll_ready` can
+    /// make progress.
+    waiters: Arc<Mutex<Option<task::Task>>>,
+    /// Keeps this request counted against the shared drain until it completes.
+    guard: Option<DrainGuard>,
+}
+
+/// A discovery-driven service that self-heals across transient connect errors.
+///
+/// Wraps the reconnecting [`Stack`] so that, rather than tearing the service
+/// down and dropping every buffered request when a connect error surfaces, it
+/// rebuilds a fresh inner stack in place. The rebuild is lazy: it does not
+/// eagerly dial, but waits for the next `poll_ready` from the buffer to
+/// attempt the new connection, so that combined with buffer cancellation we
+/// never spin forever on a dead backend.
+pub struct BoundService<B> {
+    bind: Bind<Arc<ctx::Proxy>, B>,
+    addr: SocketAddr,
+    target: BindTarget,
+    /// For an `Auto` target, the `Protocol` the current inner stack is bound
+    /// to; `None` until the first request selects one.
+    detected: Option<Protocol>,
+    retry_canceled: bool,
+    inner: Stack<B>,
+}
+
+/// How a [`BoundService`] should (re)build its inner stack.
+///
+/// `Fixed` commits to a `Protocol` up front, as `BindProtocol` does; `Auto`
+/// picks the `Protocol` from each request — HTTP/2 by its version, otherwise
+/// HTTP/1.x keyed by `Host` — so a single bind serves mixed-protocol backends,
+/// as `BindAuto` does.
+#[derive(Clone, Debug)]
+enum BindTarget {
+    Fixed(Protocol),
+    Auto,
+}
+
+/// Drives a request attempt, replaying it once on a fresh stack if the
+/// connection was canceled before the response started.
+pub struct RetryFuture<B> {
+    state: RetryState<B>,
+    /// The rebound stack and the replay request, present only while the first
+    /// attempt is still eligible to be retried.
+    replay: Option<(Stack<B>, http::Request<B>)>,
+}
+
+type StackFuture<B> = <Stack<B> as tower::Service>::Future;
+
+enum RetryState<B> {
+    /// The first attempt is in flight.
+    First(StackFuture<B>),
+    /// Awaiting readiness of a freshly bound stack: the replay stack after a
+    /// canceled first attempt, or, after an auto-detected protocol switch, the
+    /// stack for the first attempt itself.
+    Ready {
+        service: Stack<B>,
+        req: http::Request<B>,
+    },
+    /// The request is in flight on the stack readied in `Ready`.
+    Second(StackFuture<B>),
+    /// Transient state used while transitioning between the variants above.
+    Gone,
+}
+
+/// Spaces out reconnect attempts using a `Backoff` policy.
+///
+/// Wraps the `Reconnect`ed service: when an inner `poll_ready` reports a
+/// connect failure, a timer is scheduled instead of failing the requests
+/// buffered behind the service, and the buffer retries once it fires.
+pub struct WithBackoff<S, B> {
+    inner: S,
+    backoff: Backoff,
+    addr: SocketAddr,
+    handle: Handle,
+    sleeping: Option<Timeout>,
+    _p: PhantomData<B>,
+}
 
-pub type HttpResponse = http::Response<sensor::http::ResponseBody<HttpBody>>;
+pub type NewHttp<B> = sensor::NewHttp<Pooled<B>, B, RecycleBody<HttpBody>>;
+
+pub type HttpResponse = http::Response<sensor::http::ResponseBody<RecycleBody<HttpBody>>>;
 
 pub type Client<B> = transparency::Client<
     sensor::Connect<transport::Connect>,
@@ -72,6 +646,12 @@ pub type Client<B> = transparency::Client<
 pub enum BufferSpawnError {
     Inbound,
     Outbound,
+    /// A request waited longer than the configured timeout for a connection.
+    Timeout,
+    /// The inner service failed to become ready or to serve the request.
+    Unavailable,
+    /// The service is shutting down and no longer admits new requests.
+    Closed,
 }
 
 impl fmt::Display for BufferSpawnError {
@@ -88,6 +668,12 @@ impl Error for BufferSpawnError {
                 "error spawning inbound buffer task",
             BufferSpawnError::Outbound =>
                 "error spawning outbound buffer task",
+            BufferSpawnError::Timeout =>
+                "timed out waiting for a connection",
+            BufferSpawnError::Unavailable =>
+                "the backend service is unavailable",
+            BufferSpawnError::Closed =>
+                "the service is shutting down",
         }
     }
 
@@ -101,6 +687,12 @@ impl<B> Bind<(), B> {
             ctx: (),
             sensors: telemetry::Sensors::null(),
             req_ids: Default::default(),
+            backoff: Backoff::default(),
+            buffer_capacity: None,
+            request_timeout: None,
+            pool: Pool::new(PoolConfig::default()),
+            retry_canceled: false,
+            drain: Drain::default(),
             _p: PhantomData,
         }
     }
@@ -118,6 +710,12 @@ impl<B> Bind<(), B> {
             sensors: self.sensors,
             executor: self.executor,
             req_ids: self.req_ids,
+            backoff: self.backoff,
+            buffer_capacity: self.buffer_capacity,
+            request_timeout: self.request_timeout,
+            pool: self.pool,
+            retry_canceled: self.retry_canceled,
+            drain: self.drain,
             _p: PhantomData,
         }
     }
@@ -130,6 +728,12 @@ impl<C: Clone, B> Clone for Bind<C, B> {
             sensors: self.sensors.clone(),
             executor: self.executor.clone(),
             req_ids: self.req_ids.clone(),
+            backoff: self.backoff.clone(),
+            buffer_capacity: self.buffer_capacity,
+            request_timeout: self.request_timeout,
+            pool: self.pool.clone(),
+            retry_canceled: self.retry_canceled,
+            drain: self.drain.clone(),
             _p: PhantomData,
         }
     }
@@ -146,6 +750,86 @@ impl<C, B> Bind<C, B> {
         &self.executor
     }
 
+    /// Configures the decorrelated-jitter reconnect backoff.
+    ///
+    /// `base` is the initial (and post-success) delay; `cap` is the maximum
+    /// any single delay may grow to.
+    pub fn with_backoff(mut self, base: Duration, cap: Duration) -> Self {
+        self.backoff = Backoff {
+            base,
+            cap,
+            sleeps: self.backoff.sleeps,
+        };
+        self
+    }
+
+    /// Bounds in-flight concurrency to at most `capacity` requests.
+    ///
+    /// Once that many requests are in flight, `poll_ready` applies backpressure
+    /// (returns not-ready) instead of admitting more work, so a stalled backend
+    /// cannot drive unbounded concurrent work. The backpressure propagates into
+    /// the request buffer, whose queue length is bounded where it is built.
+    pub fn with_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.buffer_capacity = Some(capacity);
+        self
+    }
+
+    /// Fails a request with [`BufferSpawnError::Timeout`] if it waits longer
+    /// than `timeout` for a connection to be established.
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Configures the idle-connection pool.
+    ///
+    /// `max_idle_per_host` caps how many idle connections are retained per
+    /// `(SocketAddr, Protocol)` key; `idle_timeout` bounds how long an idle
+    /// connection may be reused before it is discarded. Pooling only applies
+    /// to HTTP/1.x backends with an authority.
+    pub fn with_pool_config(mut self, max_idle_per_host: usize, idle_timeout: Duration) -> Self {
+        self.pool = Pool {
+            config: PoolConfig {
+                max_idle_per_host,
+                idle_timeout,
+            },
+            idle: self.pool.idle,
+        };
+        self
+    }
+
+    /// Returns a connection to the idle pool once its response body completes.
+    ///
+    /// Called by the response-body completion hook so that an HTTP/1.x
+    /// connection can be checked out again on a subsequent bind.
+    pub fn recycle(&self, addr: SocketAddr, protocol: Protocol, client: Client<B>) {
+        self.pool.store((addr, protocol), client);
+    }
+
+    /// Enables opt-in replay of canceled, idempotent requests.
+    ///
+    /// When enabled, a request whose HTTP/2 stream was reset with `CANCEL` or
+    /// `REFUSED_STREAM` before any response bytes arrived is re-issued exactly
+    /// once against the rebound service, but only for idempotent methods.
+    /// Requests whose body stream has already begun being read are never
+    /// replayed. The HTTP/1 keep-alive-close case is not yet detected (see
+    /// `is_canceled`), so this currently applies to HTTP/2 only.
+    pub fn with_retry_canceled(mut self, retry_canceled: bool) -> Self {
+        self.retry_canceled = retry_canceled;
+        self
+    }
+
+    /// Signals graceful shutdown and returns a future that resolves once every
+    /// service this `Bind` has handed out has finished its in-flight work.
+    ///
+    /// After this is called, each service's `poll_ready` stops admitting new
+    /// requests while already-dispatched requests run to completion, so the
+    /// top-level proxy can await clean termination rather than severing live
+    /// streams.
+    pub fn drain_handle(&self) -> Draining {
+        self.drain.signal()
+    }
+
     // pub fn req_ids(&self) -> &Arc<AtomicUsize> {
     //     &self.req_ids
     // }
@@ -161,7 +845,109 @@ where
     B: tower_h2::Body + 'static,
 {
     pub fn bind_service(&self, addr: &SocketAddr, protocol: &Protocol) -> Service<B> {
-        trace!("bind_service addr={}, protocol={:?}", addr, protocol);
+        self.bind_bounded(addr, BindTarget::Fixed(protocol.clone()))
+    }
+
+    /// Binds a protocol-detecting service that picks its `Protocol` from each
+    /// request rather than committing to one up front.
+    pub fn bind_service_auto(&self, addr: &SocketAddr) -> Service<B> {
+        self.bind_bounded(addr, BindTarget::Auto)
+    }
+
+    /// Wraps a self-healing [`BoundService`] for `target` in the bounded buffer.
+    fn bind_bounded(&self, addr: &SocketAddr, target: BindTarget) -> Service<B> {
+        let bound = BoundService {
+            bind: self.clone(),
+            addr: *addr,
+            detected: None,
+            retry_canceled: self.retry_canceled,
+            inner: self.bind_stack_for(addr, &target),
+            target,
+        };
+
+        // Space out the self-healing service's reconnect attempts with
+        // decorrelated-jitter backoff. The backoff sits *above* the
+        // `BoundService` so that a connect error it rebinds on is also the
+        // error this layer delays the retry of.
+        let backed = WithBackoff {
+            inner: bound,
+            backoff: self.backoff.clone(),
+            addr: *addr,
+            handle: self.executor.clone(),
+            sleeping: None,
+            _p: PhantomData,
+        };
+
+        // Bound the buffer in front of the self-healing service so a stalled
+        // backend can neither grow memory without limit nor hang a request
+        // indefinitely.
+        Bounded {
+            inner: backed,
+            capacity: self.buffer_capacity,
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            waiters: Arc::new(Mutex::new(None)),
+            timeout: self.request_timeout,
+            waiting: None,
+            handle: self.executor.clone(),
+            drain: self.drain.clone(),
+            _p: PhantomData,
+        }
+    }
+
+    /// Builds the reconnecting stack for a bind target.
+    ///
+    /// A `Fixed` target binds its committed `Protocol`. An `Auto` target has no
+    /// protocol until a request arrives, so it binds HTTP/2 as a provisional
+    /// default; [`BoundService::call`] rebinds to the request-detected protocol
+    /// on the first request and whenever it changes.
+    fn bind_stack_for(&self, addr: &SocketAddr, target: &BindTarget) -> Stack<B> {
+        match *target {
+            BindTarget::Fixed(ref protocol) => self.bind_stack(addr, protocol),
+            BindTarget::Auto => self.bind_stack(addr, &Protocol::Http2),
+        }
+    }
+
+    /// Builds the reconnecting stack for a single target.
+    fn bind_stack(&self, addr: &SocketAddr, protocol: &Protocol) -> Stack<B> {
+        trace!("bind_stack addr={}, protocol={:?}", addr, protocol);
+        let client_ctx = ctx::transport::Client::new(
+            &self.ctx,
+            addr,
+            conduit_proxy_controller_grpc::common::Protocol::Http,
+        );
+
+        // `Pooled` leases an idle connection from the pool (or dials a fresh
+        // one) per request and returns it once the response body completes, so
+        // `bind_stack` hands it the key rather than a concrete connection.
+        // `Pool::store` keeps a returned connection only for poolable protocols,
+        // so leasing degenerates to a fresh dial for HTTP/2 and `NoAuthority`.
+        let client = Pooled {
+            inner: None,
+            leased_from_pool: false,
+            bind: self.clone(),
+            key: (*addr, protocol.clone()),
+        };
+
+        let proxy = self.sensors.http(self.req_ids.clone(), client, &client_ctx);
+
+        self.reconnecting(proxy, addr)
+    }
+
+    /// Leases a connection for `key`: an idle one checked out of the pool, or a
+    /// freshly dialed one on a miss. The `bool` is `true` when it came from the
+    /// pool, so the caller can discard and re-dial if it turns out to be dead.
+    fn lease(&self, key: &PoolKey) -> (Client<B>, bool) {
+        match self.pool.checkout(key) {
+            Some(client) => {
+                trace!("reusing pooled connection addr={}, protocol={:?}", key.0, key.1);
+                (client, true)
+            }
+            None => (self.connect_client(&key.0, &key.1), false),
+        }
+    }
+
+    /// Dials a fresh backend connection for `protocol`, bypassing the pool.
+    fn connect_client(&self, addr: &SocketAddr, protocol: &Protocol) -> Client<B> {
         let client_ctx = ctx::transport::Client::new(
             &self.ctx,
             addr,
@@ -171,24 +957,409 @@ where
         // Map a socket address to a connection.
         let connect = self.sensors.connect(
             transport::Connect::new(*addr, &self.executor),
-            &client_ctx
+            &client_ctx,
         );
 
-        let client = transparency::Client::new(
+        // The client selects its behavior from the protocol. HTTP/1.x and
+        // HTTP/2 run over the `transport::Connect` above. `Http3` is a
+        // placeholder connection class: no QUIC transport is wired in yet, so
+        // it is never constructed for live traffic and falls through to the
+        // same connect path rather than being served.
+        transparency::Client::new(
             protocol,
             connect,
             self.executor.clone(),
-        );
-
-        let proxy = self.sensors.http(self.req_ids.clone(), client, &client_ctx);
+        )
+    }
 
-        // Automatically perform reconnects if the connection fails.
-        //
-        // TODO: Add some sort of backoff logic.
+    /// Wraps a bound proxy in a reconnecting stack.
+    ///
+    /// Connect failures are surfaced by `Reconnect` rather than swallowed here,
+    /// so the [`BoundService`] above can rebind and the [`WithBackoff`] layer
+    /// can space out the retry.
+    fn reconnecting(&self, proxy: NewHttp<B>, _addr: &SocketAddr) -> Stack<B> {
         Reconnect::new(proxy)
     }
 }
 
+// ===== impl WithBackoff =====
+
+impl<S, B> tower::Service for WithBackoff<S, B>
+where
+    S: tower::Service<Request = http::Request<B>>,
+{
+    type Request = S::Request;
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self) -> Poll<(), Self::Error> {
+        // While a backoff timer is pending, hold the buffer back rather than
+        // letting the inner service retry the dead backend immediately.
+        if let Some(mut sleep) = self.sleeping.take() {
+            match sleep.poll() {
+                Ok(Async::Ready(())) => {}
+                Ok(Async::NotReady) => {
+                    self.sleeping = Some(sleep);
+                    return Ok(Async::NotReady);
+                }
+                // A timer error should not wedge the service; fall through and
+                // let the inner service make its own attempt.
+                Err(_) => {}
+            }
+        }
+
+        match self.inner.poll_ready() {
+            Ok(Async::Ready(())) => {
+                // A successful readiness means the connection is live again.
+                self.backoff.reset(&self.addr);
+                Ok(Async::Ready(()))
+            }
+            Ok(Async::NotReady) => Ok(Async::NotReady),
+            Err(e) => {
+                // Schedule the next attempt instead of failing the buffered
+                // requests, and ask to be polled again once it fires.
+                let delay = self.backoff.advance(&self.addr);
+                trace!("backoff addr={} delay={:?}", self.addr, delay);
+                match Timeout::new(delay, &self.handle) {
+                    Ok(mut timeout) => {
+                        // Poll the new timer once so this task is woken when it
+                        // fires; if it already has, ask to be polled right away.
+                        match timeout.poll() {
+                            Ok(Async::NotReady) => self.sleeping = Some(timeout),
+                            _ => task::current().notify(),
+                        }
+                        Ok(Async::NotReady)
+                    }
+                    // If we cannot arm a timer, surface the original error.
+                    Err(_) => Err(e),
+                }
+            }
+        }
+    }
+
+    fn call(&mut self, req: Self::Request) -> Self::Future {
+        self.inner.call(req)
+    }
+}
+
+// ===== impl BoundService =====
+
+impl<B> tower::Service for BoundService<B>
+where
+    B: tower_h2::Body + Default + 'static,
+    <Stack<B> as tower::Service>::Error: Error + 'static,
+{
+    type Request = <Stack<B> as tower::Service>::Request;
+    type Response = <Stack<B> as tower::Service>::Response;
+    type Error = <Stack<B> as tower::Service>::Error;
+    type Future = RetryFuture<B>;
+
+    fn poll_ready(&mut self) -> Poll<(), Self::Error> {
+        match self.inner.poll_ready() {
+            Err(e) => {
+                // The connection failed. Rebuild a fresh inner stack in place
+                // so the next attempt dials anew rather than leaving a wedged
+                // `Reconnect` behind, and surface the error to the backoff
+                // layer above so it can space out that next attempt instead of
+                // letting the buffer drop every request behind us. We do not
+                // dial here: the rebound stack connects on its next poll.
+                trace!("rebinding service addr={}", self.addr);
+                self.inner = self.bind.bind_stack_for(&self.addr, &self.target);
+                Err(e)
+            }
+            other => other,
+        }
+    }
+
+    fn call(&mut self, req: Self::Request) -> Self::Future {
+        // For an auto-detected bind, select the concrete protocol from this
+        // request — HTTP/2 by version, otherwise HTTP/1.x keyed by `Host` — and
+        // rebind the inner stack whenever it differs from the one in place, so
+        // a single `BindAuto` serves mixed HTTP/1.1 and HTTP/2 backends without
+        // a pre-determined `Protocol`. A rebind leaves the persistent stack
+        // unconnected, so we do not dispatch on it here; see below.
+        let rebound = if let BindTarget::Auto = self.target {
+            let protocol = Protocol::from(&req);
+            if self.detected.as_ref() != Some(&protocol) {
+                trace!("auto-detected protocol addr={} protocol={:?}", self.addr, protocol);
+                self.inner = self.bind.bind_stack(&self.addr, &protocol);
+                self.detected = Some(protocol.clone());
+                Some(protocol)
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        // A request is only safe to replay if it is idempotent *and* carries no
+        // body to re-read: once a body stream has begun being consumed it
+        // cannot be reproduced, so we require an end-of-stream body and rebuild
+        // the request head onto a fresh empty body rather than cloning `B`.
+        let replay = if self.retry_canceled
+            && is_idempotent(req.method())
+            && req.body().is_end_stream()
+        {
+            let mut replay = http::Request::new(B::default());
+            *replay.method_mut() = req.method().clone();
+            *replay.uri_mut() = req.uri().clone();
+            *replay.version_mut() = req.version();
+            *replay.headers_mut() = req.headers().clone();
+            // Replay on the protocol the first attempt actually used: for an
+            // `Auto` bind that is the protocol just detected from this request
+            // (recorded in `self.detected`), not the provisional `Auto`
+            // default, so an HTTP/1 request is not re-issued on an HTTP/2 stack.
+            let protocol = match self.target {
+                BindTarget::Fixed(ref protocol) => protocol.clone(),
+                BindTarget::Auto => self.detected.clone()
+                    .expect("auto detection records the protocol before replay"),
+            };
+            let service = self.bind.bind_stack(&self.addr, &protocol);
+            Some((service, replay))
+        } else {
+            None
+        };
+
+        // If the protocol just changed, `self.inner` is a fresh `Reconnect`
+        // that has not dialed yet — calling it now would dispatch on an unready
+        // service. Instead drive a freshly bound stack to readiness before
+        // dispatching this request (the only extra dial happens on the rare
+        // protocol switch; steady-state requests reuse the warm `self.inner`,
+        // already readied by `poll_ready`).
+        let state = match rebound {
+            Some(protocol) => {
+                let service = self.bind.bind_stack(&self.addr, &protocol);
+                RetryState::Ready { service, req }
+            }
+            None => RetryState::First(self.inner.call(req)),
+        };
+
+        RetryFuture {
+            state,
+            replay,
+        }
+    }
+}
+
+// ===== impl RetryFuture =====
+
+impl<B> Future for RetryFuture<B>
+where
+    B: tower_h2::Body + Default + 'static,
+    <Stack<B> as tower::Service>::Error: Error + 'static,
+{
+    type Item = <Stack<B> as tower::Service>::Response;
+    type Error = <Stack<B> as tower::Service>::Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        loop {
+            match mem::replace(&mut self.state, RetryState::Gone) {
+                RetryState::First(mut fut) => match fut.poll() {
+                    Ok(Async::Ready(rsp)) => return Ok(Async::Ready(rsp)),
+                    Ok(Async::NotReady) => {
+                        self.state = RetryState::First(fut);
+                        return Ok(Async::NotReady);
+                    }
+                    Err(e) => {
+                        match self.replay.take() {
+                            // The connection was closed before the response
+                            // started, so the request is safe to replay once.
+                            Some((service, req)) if is_canceled(&e) => {
+                                trace!("retrying canceled request");
+                                self.state = RetryState::Ready { service, req };
+                            }
+                            // Otherwise surface the original error unchanged.
+                            _ => return Err(e),
+                        }
+                    }
+                },
+                RetryState::Ready { mut service, req } => match service.poll_ready() {
+                    Ok(Async::Ready(())) => {
+                        self.state = RetryState::Second(service.call(req));
+                    }
+                    Ok(Async::NotReady) => {
+                        self.state = RetryState::Ready { service, req };
+                        return Ok(Async::NotReady);
+                    }
+                    Err(e) => return Err(e),
+                },
+                RetryState::Second(mut fut) => match fut.poll() {
+                    Ok(Async::Ready(rsp)) => return Ok(Async::Ready(rsp)),
+                    Ok(Async::NotReady) => {
+                        self.state = RetryState::Second(fut);
+                        return Ok(Async::NotReady);
+                    }
+                    Err(e) => match self.replay.take() {
+                        // After a protocol switch this was the first attempt,
+                        // so a canceled one may still be replayed once.
+                        Some((service, req)) if is_canceled(&e) => {
+                            trace!("retrying canceled request");
+                            self.state = RetryState::Ready { service, req };
+                        }
+                        _ => return Err(e),
+                    },
+                },
+                RetryState::Gone => panic!("RetryFuture polled after completion"),
+            }
+        }
+    }
+}
+
+/// Whether a request's method permits it to be safely replayed.
+fn is_idempotent(method: &http::Method) -> bool {
+    use http::Method;
+    match *method {
+        Method::GET
+        | Method::HEAD
+        | Method::PUT
+        | Method::DELETE
+        | Method::OPTIONS
+        | Method::TRACE => true,
+        _ => false,
+    }
+}
+
+/// Detects the "stream reset before any response bytes were received"
+/// condition, the only case in which a dispatched request is safe to replay.
+///
+/// Walks the error's source chain for an HTTP/2 reset carrying `CANCEL` or
+/// `REFUSED_STREAM`, the reasons a peer signals when it never began processing
+/// the request. Matching on the typed reason avoids depending on the wording
+/// of an error message or on the deprecated `Error::description`.
+///
+/// This covers HTTP/2 only. The analogous HTTP/1 case — a keep-alive
+/// connection closed after the request was dispatched but before any response
+/// bytes arrived — produces no `h2::Reason` and is not detected here; it would
+/// need a typed signal from `transparency::Client` that this build does not
+/// surface, so HTTP/1 requests are never replayed.
+fn is_canceled<E: Error + 'static>(err: &E) -> bool {
+    let mut cause: Option<&(Error + 'static)> = Some(err);
+    while let Some(err) = cause {
+        if let Some(h2) = err.downcast_ref::<h2::Error>() {
+            return match h2.reason() {
+                Some(h2::Reason::CANCEL) | Some(h2::Reason::REFUSED_STREAM) => true,
+                _ => false,
+            };
+        }
+        cause = err.source();
+    }
+    false
+}
+
+// ===== impl Bounded =====
+
+impl<S, B> tower::Service for Bounded<S, B>
+where
+    S: tower::Service<Request = http::Request<B>>,
+{
+    type Request = S::Request;
+    type Response = S::Response;
+    type Error = BufferSpawnError;
+    type Future = BoundedFuture<S::Future>;
+
+    fn poll_ready(&mut self) -> Poll<(), Self::Error> {
+        // Once shutdown is signaled, stop admitting new requests by closing
+        // this service. Returning an error (rather than a bare `NotReady` with
+        // no registered waker) retires the buffer in front of us instead of
+        // parking its queued requests forever; requests already dispatched run
+        // to completion independently via their `BoundedFuture`s, which the
+        // `Draining` future awaits before the proxy terminates.
+        if self.drain.is_draining() {
+            return Err(BufferSpawnError::Closed);
+        }
+
+        // At capacity, apply backpressure rather than dispatching more work or
+        // failing the service permanently: park this task and let a completing
+        // request wake it once a slot frees. Backpressure flows up into the
+        // request buffer, which bounds its own queue length.
+        if let Some(capacity) = self.capacity {
+            if self.in_flight.load(Ordering::SeqCst) >= capacity {
+                *self.waiters.lock().expect("bounded waiter lock poisoned") =
+                    Some(task::current());
+                // Re-check after registering so a slot that freed during
+                // registration is not missed.
+                if self.in_flight.load(Ordering::SeqCst) >= capacity {
+                    return Ok(Async::NotReady);
+                }
+            }
+        }
+
+        match self.inner.poll_ready() {
+            Ok(Async::Ready(())) => {
+                // The connection is established; stop timing the wait so the
+                // request's response stream is not subject to this bound.
+                self.waiting = None;
+                Ok(Async::Ready(()))
+            }
+            Ok(Async::NotReady) => {
+                // Still waiting for a connection. Arm the timer on the first
+                // such poll and fail the request if it fires before readiness.
+                if let Some(timeout) = self.timeout {
+                    let mut waiting = match self.waiting.take() {
+                        Some(waiting) => waiting,
+                        None => match Timeout::new(timeout, &self.handle) {
+                            Ok(waiting) => waiting,
+                            // Without a timer, fall back to an unbounded wait.
+                            Err(_) => return Ok(Async::NotReady),
+                        },
+                    };
+                    if let Ok(Async::Ready(())) = waiting.poll() {
+                        return Err(BufferSpawnError::Timeout);
+                    }
+                    self.waiting = Some(waiting);
+                }
+                Ok(Async::NotReady)
+            }
+            Err(_) => Err(BufferSpawnError::Unavailable),
+        }
+    }
+
+    fn call(&mut self, req: Self::Request) -> Self::Future {
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        BoundedFuture {
+            inner: self.inner.call(req),
+            in_flight: self.in_flight.clone(),
+            waiters: self.waiters.clone(),
+            guard: Some(self.drain.guard()),
+        }
+    }
+}
+
+impl<F: Future> BoundedFuture<F> {
+    /// Frees this request's concurrency slot and wakes a task backpressured on
+    /// capacity, exactly once per future.
+    fn release(&mut self) {
+        if self.guard.take().is_some() {
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            if let Some(task) = self.waiters.lock().expect("bounded waiter lock poisoned").take() {
+                task.notify();
+            }
+        }
+    }
+}
+
+impl<F: Future> Future for BoundedFuture<F> {
+    type Item = F::Item;
+    type Error = BufferSpawnError;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        // The connection-establishment timeout is enforced in `poll_ready`, so
+        // a legitimately slow or streaming response is never failed here.
+        match self.inner.poll() {
+            Ok(Async::NotReady) => Ok(Async::NotReady),
+            Ok(Async::Ready(rsp)) => {
+                self.release();
+                Ok(Async::Ready(rsp))
+            }
+            Err(_) => {
+                self.release();
+                Err(BufferSpawnError::Unavailable)
+            }
+        }
+    }
+}
+
 // ===== impl BindProtocol =====
 
 
@@ -199,11 +1370,34 @@ impl<C, B> Bind<C, B> {
             protocol,
         }
     }
+
+    /// Defers the `Protocol` decision to per-request detection, yielding a
+    /// `Bind` that serves mixed-protocol backends.
+    pub fn with_protocol_detection(self) -> BindAuto<C, B> {
+        BindAuto { bind: self }
+    }
+}
+
+impl<B> control::discovery::Bind for BindAuto<Arc<ctx::Proxy>, B>
+where
+    B: tower_h2::Body + Default + 'static,
+    <Stack<B> as tower::Service>::Error: Error + 'static,
+{
+    type Request = http::Request<B>;
+    type Response = HttpResponse;
+    type Error = <Service<B> as tower::Service>::Error;
+    type Service = Service<B>;
+    type BindError = ();
+
+    fn bind(&self, addr: &SocketAddr) -> Result<Self::Service, Self::BindError> {
+        Ok(self.bind.bind_service_auto(addr))
+    }
 }
 
 impl<B> control::discovery::Bind for BindProtocol<Arc<ctx::Proxy>, B>
 where
-    B: tower_h2::Body + 'static,
+    B: tower_h2::Body + Default + 'static,
+    <Stack<B> as tower::Service>::Error: Error + 'static,
 {
     type Request = http::Request<B>;
     type Response = HttpResponse;
@@ -259,3 +1453,97 @@ impl cmp::PartialEq for Host {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn authority(s: &str) -> Host {
+        Host::Authority(s.parse::<uri::Authority>().unwrap())
+    }
+
+    #[test]
+    fn no_authority_is_never_equal() {
+        assert_ne!(Host::NoAuthority, Host::NoAuthority);
+        assert_eq!(authority("example.com"), authority("example.com"));
+        assert_ne!(authority("example.com"), authority("other.com"));
+        assert_ne!(authority("example.com"), Host::NoAuthority);
+    }
+
+    #[test]
+    fn idempotent_methods_are_replayable() {
+        use http::Method;
+        for method in &[Method::GET, Method::HEAD, Method::PUT,
+                        Method::DELETE, Method::OPTIONS, Method::TRACE] {
+            assert!(is_idempotent(method), "{} should be idempotent", method);
+        }
+        for method in &[Method::POST, Method::PATCH, Method::CONNECT] {
+            assert!(!is_idempotent(method), "{} should not be idempotent", method);
+        }
+    }
+
+    #[test]
+    fn rand_between_stays_within_bounds() {
+        let low = Duration::from_millis(50);
+        let high = Duration::from_millis(500);
+        for _ in 0..1_000 {
+            let d = rand_between(low, high);
+            assert!(d >= low && d <= high);
+        }
+        // A degenerate range collapses to its lower bound.
+        assert_eq!(rand_between(high, low), high);
+        assert_eq!(rand_between(low, low), low);
+    }
+
+    #[test]
+    fn backoff_advance_is_bounded_and_resets() {
+        let base = Duration::from_millis(50);
+        let cap = Duration::from_millis(400);
+        let backoff = Backoff { base, cap, sleeps: Default::default() };
+        let addr: SocketAddr = "127.0.0.1:8080".parse().unwrap();
+
+        for _ in 0..1_000 {
+            let d = backoff.advance(&addr);
+            assert!(d >= base && d <= cap, "delay {:?} out of [{:?}, {:?}]", d, base, cap);
+        }
+
+        // Reset clears the per-address state so the next attempt starts over.
+        backoff.reset(&addr);
+        assert!(!backoff.sleeps.lock().unwrap().contains_key(&addr));
+    }
+
+    #[test]
+    fn only_http1_with_authority_is_poolable() {
+        let enabled = Pool::<()>::new(PoolConfig {
+            max_idle_per_host: 4,
+            idle_timeout: Duration::from_secs(90),
+        });
+        assert!(enabled.poolable(&Protocol::Http1(authority("example.com"))));
+        assert!(!enabled.poolable(&Protocol::Http1(Host::NoAuthority)));
+        assert!(!enabled.poolable(&Protocol::Http2));
+        assert!(!enabled.poolable(&Protocol::Http3(authority("example.com"))));
+
+        // Pooling is off by default until the caller opts in.
+        let disabled = Pool::<()>::new(PoolConfig::default());
+        assert!(!disabled.poolable(&Protocol::Http1(authority("example.com"))));
+    }
+
+    #[test]
+    fn drain_counts_outstanding_and_signals() {
+        let drain = Drain::default();
+        assert!(!drain.is_draining());
+
+        let g1 = drain.guard();
+        let g2 = drain.guard();
+        assert_eq!(drain.shared.outstanding.load(Ordering::SeqCst), 2);
+
+        drop(g1);
+        assert_eq!(drain.shared.outstanding.load(Ordering::SeqCst), 1);
+
+        drain.signal();
+        assert!(drain.is_draining());
+
+        drop(g2);
+        assert_eq!(drain.shared.outstanding.load(Ordering::SeqCst), 0);
+    }
+}